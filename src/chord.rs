@@ -0,0 +1,147 @@
+const SHARP_NOTES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+const FLAT_NOTES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+const FLAT_KEYS: [&str; 6] = ["F", "Bb", "Eb", "Ab", "Db", "Gb"];
+
+/// A chord name split into the parts that matter for transposition: the
+/// root note gets shifted by semitone, the suffix (quality, extensions)
+/// is left untouched, and an optional slash bass is shifted the same way
+/// as the root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chord {
+    pub root: String,
+    pub suffix: String,
+    pub bass: Option<String>,
+}
+
+impl Chord {
+    pub fn parse(name: &str) -> Option<Chord> {
+        let (body, bass) = match name.split_once('/') {
+            Some((body, bass)) => (body, Some(parse_note(bass)?)),
+            None => (name, None),
+        };
+        let (root, suffix) = split_root(body)?;
+        Some(Chord { root, suffix, bass })
+    }
+
+    /// Shifts the chord by `semitones`, spelling the result with flats if
+    /// `use_flats` is set (typically because the song's key is a flat key)
+    /// and with sharps otherwise.
+    pub fn transpose(&self, semitones: i32, use_flats: bool) -> Chord {
+        Chord {
+            root: transpose_note(&self.root, semitones, use_flats),
+            suffix: self.suffix.clone(),
+            bass: self
+                .bass
+                .as_ref()
+                .map(|bass| transpose_note(bass, semitones, use_flats)),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        match &self.bass {
+            Some(bass) => format!("{}{}/{}", self.root, self.suffix, bass),
+            None => format!("{}{}", self.root, self.suffix),
+        }
+    }
+}
+
+/// Splits a chord body into its root note (matching a two-character
+/// accidental before falling back to a single letter) and the remaining
+/// quality/suffix text.
+fn split_root(body: &str) -> Option<(String, String)> {
+    let mut chars = body.chars();
+    let letter = chars.next()?;
+    if !letter.is_ascii_uppercase() {
+        return None;
+    }
+    let rest = chars.as_str();
+    if let Some(accidental) = rest.chars().next() {
+        if accidental == '#' || accidental == 'b' {
+            let root = format!("{letter}{accidental}");
+            return Some((root, rest[1..].to_string()));
+        }
+    }
+    Some((letter.to_string(), rest.to_string()))
+}
+
+fn parse_note(text: &str) -> Option<String> {
+    let mut chars = text.chars();
+    let letter = chars.next()?;
+    if !letter.is_ascii_uppercase() {
+        return None;
+    }
+    match chars.next() {
+        Some(accidental) if accidental == '#' || accidental == 'b' => {
+            Some(format!("{letter}{accidental}"))
+        }
+        _ => Some(letter.to_string()),
+    }
+}
+
+pub(crate) fn pitch_index(note: &str) -> Option<usize> {
+    SHARP_NOTES
+        .iter()
+        .position(|n| *n == note)
+        .or_else(|| FLAT_NOTES.iter().position(|n| *n == note))
+}
+
+/// Whether a song in the given key is conventionally notated with flats
+/// rather than sharps.
+pub fn key_prefers_flats(tonic: &str) -> bool {
+    FLAT_KEYS.contains(&tonic)
+}
+
+fn transpose_note(note: &str, semitones: i32, use_flats: bool) -> String {
+    match pitch_index(note) {
+        Some(idx) => {
+            let new_idx = (idx as i32 + semitones).rem_euclid(12) as usize;
+            if use_flats {
+                FLAT_NOTES[new_idx].to_string()
+            } else {
+                SHARP_NOTES[new_idx].to_string()
+            }
+        }
+        None => note.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_root_suffix_and_bass() {
+        let chord = Chord::parse("C#m7/G#").unwrap();
+        assert_eq!(chord.root, "C#");
+        assert_eq!(chord.suffix, "m7");
+        assert_eq!(chord.bass.as_deref(), Some("G#"));
+    }
+
+    #[test]
+    fn transpose_defaults_to_sharps() {
+        let chord = Chord::parse("C").unwrap();
+        assert_eq!(chord.transpose(1, false).render(), "C#");
+    }
+
+    #[test]
+    fn transpose_uses_flats_when_requested() {
+        let chord = Chord::parse("C").unwrap();
+        assert_eq!(chord.transpose(1, true).render(), "Db");
+    }
+
+    #[test]
+    fn transpose_leaves_suffix_alone_and_shifts_bass_with_root() {
+        let chord = Chord::parse("Am7/C").unwrap();
+        assert_eq!(chord.transpose(2, false).render(), "Bm7/D");
+    }
+
+    #[test]
+    fn key_prefers_flats_matches_flat_keys() {
+        assert!(key_prefers_flats("Bb"));
+        assert!(!key_prefers_flats("G"));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::chord::Chord;
+
+/// A fingering for one chord: one entry per string, low E to high E.
+/// `None` means the string is muted; `Some(n)` means it's fretted `n`
+/// frets above the nut.
+pub struct Diagram {
+    pub name: String,
+    /// For a derived barre shape, the root's real fret, which may be 0 for a
+    /// shape rooted on an open string; chords from `OPEN_CHORDS` use 1.
+    /// Display code clamps this to 1 for the "Nfr" label.
+    pub base_fret: u8,
+    pub frets: [Option<u8>; 6],
+}
+
+/// Common open-position fingerings, taken straight off a chord chart
+/// (e.g. C = `x32010`, G = `320003`, Am = `x02210`).
+const OPEN_CHORDS: &[(&str, &str)] = &[
+    ("C", "x32010"),
+    ("G", "320003"),
+    ("Am", "x02210"),
+    ("D", "xx0232"),
+    ("E", "022100"),
+    ("A", "x02220"),
+    ("Em", "022000"),
+    ("Dm", "xx0231"),
+    ("F", "133211"),
+];
+
+pub fn diagram_for(name: &str) -> Diagram {
+    if let Some((_, pattern)) = OPEN_CHORDS
+        .iter()
+        .find(|(chord_name, _)| *chord_name == name)
+    {
+        return parse_pattern(name, pattern, 1);
+    }
+
+    derive_barre_diagram(name)
+}
+
+fn parse_pattern(name: &str, pattern: &str, base_fret: u8) -> Diagram {
+    let mut frets = [None; 6];
+    for (i, c) in pattern.chars().enumerate().take(6) {
+        frets[i] = c.to_digit(10).map(|d| d as u8);
+    }
+    Diagram {
+        name: name.to_string(),
+        base_fret,
+        frets,
+    }
+}
+
+/// Derives a movable barre shape for a chord that isn't in `OPEN_CHORDS`:
+/// finds the root's fret on the low-E or A string (whichever puts the
+/// barre closer to the nut) and applies the quality's interval template.
+fn derive_barre_diagram(name: &str) -> Diagram {
+    let Some(chord) = Chord::parse(name) else {
+        return Diagram {
+            name: name.to_string(),
+            base_fret: 1,
+            frets: [None; 6],
+        };
+    };
+
+    let low_e_fret = fret_on_string(&chord.root, "E");
+    let a_fret = fret_on_string(&chord.root, "A");
+    let (e_offsets, a_offsets) = shape_offsets(&chord.suffix);
+
+    let (base_fret, offsets) = if low_e_fret <= a_fret {
+        (low_e_fret, e_offsets)
+    } else {
+        (a_fret, a_offsets)
+    };
+
+    // `base_fret` is the root's real fret (0 for an open string) and is
+    // used as-is here so shapes rooted on an open string keep their open
+    // notes. Display code clamps it to 1 for the "Nfr" label and marker
+    // row — see `render_diagram_lines` in ui.rs.
+    let frets = offsets.map(|offset| offset.map(|o| base_fret + o));
+
+    Diagram {
+        name: name.to_string(),
+        base_fret,
+        frets,
+    }
+}
+
+fn fret_on_string(root: &str, open_string_note: &str) -> u8 {
+    let root_idx = crate::chord::pitch_index(root).unwrap_or(0) as i32;
+    let open_idx = crate::chord::pitch_index(open_string_note).unwrap_or(0) as i32;
+    (root_idx - open_idx).rem_euclid(12) as u8
+}
+
+/// Per-quality fret offsets relative to the root, for a barre rooted on
+/// the low-E string and one rooted on the A string respectively.
+fn shape_offsets(suffix: &str) -> ([Option<u8>; 6], [Option<u8>; 6]) {
+    match suffix {
+        "m" | "min" | "-" => (
+            [Some(0), Some(2), Some(2), Some(0), Some(0), Some(0)],
+            [None, Some(0), Some(2), Some(2), Some(1), Some(0)],
+        ),
+        "7" => (
+            [Some(0), Some(2), Some(0), Some(1), Some(0), Some(0)],
+            [None, Some(0), Some(2), Some(0), Some(2), Some(0)],
+        ),
+        "m7" => (
+            [Some(0), Some(2), Some(0), Some(0), Some(0), Some(0)],
+            [None, Some(0), Some(2), Some(0), Some(1), Some(0)],
+        ),
+        "sus4" => (
+            [Some(0), Some(2), Some(2), Some(2), Some(0), Some(0)],
+            [None, Some(0), Some(2), Some(2), Some(3), Some(0)],
+        ),
+        _ => (
+            [Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)],
+            [None, Some(0), Some(2), Some(2), Some(2), Some(0)],
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_chords_are_returned_verbatim() {
+        let diagram = diagram_for("C");
+        assert_eq!(diagram.base_fret, 1);
+        assert_eq!(
+            diagram.frets,
+            [None, Some(3), Some(2), Some(0), Some(1), Some(0)]
+        );
+    }
+
+    #[test]
+    fn derives_a_movable_major_barre_shape_rooted_on_the_a_string() {
+        // Bb isn't in OPEN_CHORDS, so this exercises derive_barre_diagram.
+        let diagram = diagram_for("Bb");
+        assert_eq!(diagram.base_fret, 1);
+        assert_eq!(
+            diagram.frets,
+            [None, Some(1), Some(3), Some(3), Some(3), Some(1)]
+        );
+    }
+
+    #[test]
+    fn derives_a_movable_minor_barre_shape_rooted_on_the_low_e_string() {
+        let diagram = diagram_for("Fm");
+        assert_eq!(diagram.base_fret, 1);
+        assert_eq!(
+            diagram.frets,
+            [Some(1), Some(3), Some(3), Some(1), Some(1), Some(1)]
+        );
+    }
+
+    #[test]
+    fn keeps_open_strings_for_a_shape_rooted_on_an_open_string() {
+        // E7 isn't in OPEN_CHORDS, and its root sits on the open low-E
+        // string, so this exercises the fret-0 case of derive_barre_diagram.
+        let diagram = diagram_for("E7");
+        assert_eq!(diagram.base_fret, 0);
+        assert_eq!(
+            diagram.frets,
+            [Some(0), Some(2), Some(0), Some(1), Some(0), Some(0)]
+        );
+    }
+}
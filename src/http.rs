@@ -0,0 +1,57 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use reqwest::blocking::Client;
+
+use crate::song::{self, Song};
+
+/// Long enough for a slow tab page, short enough that a dead host doesn't
+/// hang the UI indefinitely.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+pub enum FetchOutcome {
+    Success {
+        url: String,
+        html: String,
+        song: Song,
+    },
+    Failure(String),
+}
+
+/// Builds the blocking HTTP client shared by every fetch. The TLS backend
+/// is chosen at compile time via Cargo features (`default-tls`,
+/// `rustls-tls-webpki-roots`, `rustls-tls-native-roots`) so users on
+/// restricted platforms can pick a stack that actually works for them,
+/// mirroring the configurable-TLS setup in rustypipe.
+pub fn build_client() -> Client {
+    let builder = Client::builder().timeout(FETCH_TIMEOUT);
+
+    #[cfg(feature = "rustls-tls-webpki-roots")]
+    let builder = builder.use_rustls_tls();
+
+    #[cfg(feature = "rustls-tls-native-roots")]
+    let builder = builder.use_rustls_tls().tls_built_in_root_certs(true);
+
+    builder.build().expect("failed to build HTTP client")
+}
+
+/// Fetches `url` on a background thread so the event loop never blocks on
+/// the network, sending the parsed result back over the returned channel.
+pub fn spawn_fetch(url: String) -> mpsc::Receiver<FetchOutcome> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let client = build_client();
+        let outcome = match client.get(&url).send() {
+            Ok(resp) if resp.status().is_success() => match resp.text() {
+                Ok(html) => {
+                    let song = song::parse_html(&html);
+                    FetchOutcome::Success { url, html, song }
+                }
+                Err(e) => FetchOutcome::Failure(format!("Error reading response: {e}")),
+            },
+            Ok(resp) => FetchOutcome::Failure(format!("HTTP error: {}", resp.status())),
+            Err(e) => FetchOutcome::Failure(format!("Error fetching URL: {e}")),
+        };
+        let _ = tx.send(outcome);
+    });
+    rx
+}
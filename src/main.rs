@@ -5,33 +5,57 @@ use crossterm::{
 };
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
-    widgets::{Block, Borders, Paragraph},
-    Frame, Terminal,
+    Terminal,
 };
 use std::{
     error::Error,
     io::{self, Write},
+    sync::mpsc,
+    time::{Duration, Instant},
 };
-use scraper::{Html, Selector};
 
-struct Chord {
-    name: String,
-    // Add more fields like position or timing later
-}
+mod chord;
+mod fretboard;
+mod http;
+mod search;
+mod song;
+mod store;
+mod ui;
 
-struct Song {
-    title: Option<String>,
-    artist: Option<String>,
-    chords: Vec<Chord>,
-}
+use http::FetchOutcome;
+use search::{SearchOutcome, SearchResult};
+use song::Song;
+use store::Store;
+use ui::ui;
+
+/// How often the event loop ticks when idle, driving auto-scroll even with
+/// no key input.
+const TICK_RATE: Duration = Duration::from_millis(200);
 
 struct App {
     input_mode: bool,
     url: String,
     message: String,
     song: Option<Song>,
+    transpose_offset: i32,
+    search_mode: bool,
+    search_query: String,
+    search_results: Vec<SearchResult>,
+    selected_result: usize,
+    searching: bool,
+    search_rx: Option<mpsc::Receiver<SearchOutcome>>,
+    store: Store,
+    browsing_library: bool,
+    selected_library: usize,
+    auto_scroll: bool,
+    scroll_paused: bool,
+    scroll_offset: u16,
+    scroll_speed: u16,
+    last_tick: Instant,
+    show_diagrams: bool,
+    fetching: bool,
+    fetch_rx: Option<mpsc::Receiver<FetchOutcome>>,
+    spinner_frame: usize,
 }
 
 impl App {
@@ -41,6 +65,25 @@ impl App {
             url: String::new(),
             message: String::new(),
             song: None,
+            transpose_offset: 0,
+            search_mode: false,
+            search_query: String::new(),
+            search_results: Vec::new(),
+            selected_result: 0,
+            searching: false,
+            search_rx: None,
+            store: Store::load(),
+            browsing_library: false,
+            selected_library: 0,
+            auto_scroll: false,
+            scroll_paused: false,
+            scroll_offset: 0,
+            scroll_speed: 1,
+            last_tick: Instant::now(),
+            show_diagrams: false,
+            fetching: false,
+            fetch_rx: None,
+            spinner_frame: 0,
         }
     }
 }
@@ -73,25 +116,92 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn parse_html(html: &str) -> Song {
-    let document = Html::parse_document(html);
-
-    // Extract title
-    let title_selector = Selector::parse("title").unwrap();
-    let title = document.select(&title_selector).next().map(|t| t.inner_html());
+/// Kicks off a background fetch of `url` and switches the UI into its
+/// "Fetching…" state; the result is picked up later by `poll_fetch`.
+/// Shared by the raw-URL input flow and by loading a song picked from
+/// search results.
+fn begin_fetch(app: &mut App, url: String) {
+    app.fetching = true;
+    app.spinner_frame = 0;
+    app.message = "Fetching...".to_string();
+    app.fetch_rx = Some(http::spawn_fetch(url));
+}
 
-    // Extract artist (assuming from meta or h1, adjust selector as needed)
-    let artist_selector = Selector::parse("meta[name='og:site_name']").unwrap(); // Placeholder, may need better selector
-    let artist = document.select(&artist_selector).next().and_then(|a| a.value().attr("content")).map(String::from);
+/// Checks whether the in-flight background fetch (if any) has finished,
+/// and if so saves the HTML, updates the library, and loads the song.
+fn poll_fetch(app: &mut App) {
+    let Some(rx) = &app.fetch_rx else { return };
+    match rx.try_recv() {
+        Ok(FetchOutcome::Success {
+            url,
+            html,
+            mut song,
+        }) => {
+            song.source_url = Some(url);
+            if std::fs::File::create("fetched.html")
+                .and_then(|mut file| file.write_all(html.as_bytes()))
+                .is_ok()
+            {
+                let line_count = song.tab_lines.len();
+                let title = song.title.clone().unwrap_or_else(|| "Unknown".to_string());
+                let artist = song.artist.clone().unwrap_or_else(|| "Unknown".to_string());
+                app.message = format!(
+                    "Saved to fetched.html. Parsed {line_count} tab lines for '{title}' by {artist}"
+                );
+            } else {
+                app.message = "Error writing to file".to_string();
+            }
+            app.store.insert(song.clone());
+            app.song = Some(song);
+            app.transpose_offset = 0;
+            app.fetching = false;
+            app.fetch_rx = None;
+        }
+        Ok(FetchOutcome::Failure(message)) => {
+            app.message = message;
+            app.fetching = false;
+            app.fetch_rx = None;
+        }
+        Err(mpsc::TryRecvError::Empty) => {}
+        Err(mpsc::TryRecvError::Disconnected) => {
+            app.fetching = false;
+            app.fetch_rx = None;
+        }
+    }
+}
 
-    // Extract chords
-    let chord_selector = Selector::parse("span.yvpjZ").unwrap();
-    let chords: Vec<Chord> = document.select(&chord_selector).map(|el| Chord { name: el.inner_html() }).collect();
+/// Kicks off a background UG search for `query` and switches the UI into
+/// its "Searching…" state; the result is picked up later by `poll_search`.
+fn begin_search(app: &mut App, query: String) {
+    app.searching = true;
+    app.spinner_frame = 0;
+    app.message = "Searching...".to_string();
+    app.search_rx = Some(search::spawn_search(query));
+}
 
-    Song {
-        title,
-        artist,
-        chords,
+/// Checks whether the in-flight background search (if any) has finished,
+/// and if so populates `app.search_results`.
+fn poll_search(app: &mut App) {
+    let Some(rx) = &app.search_rx else { return };
+    match rx.try_recv() {
+        Ok(SearchOutcome::Success(results)) => {
+            app.message = format!("Found {} results", results.len());
+            app.search_results = results;
+            app.selected_result = 0;
+            app.searching = false;
+            app.search_rx = None;
+        }
+        Ok(SearchOutcome::Failure(message)) => {
+            app.message = message;
+            app.search_results.clear();
+            app.searching = false;
+            app.search_rx = None;
+        }
+        Err(mpsc::TryRecvError::Empty) => {}
+        Err(mpsc::TryRecvError::Disconnected) => {
+            app.searching = false;
+            app.search_rx = None;
+        }
     }
 }
 
@@ -99,46 +209,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
     loop {
         terminal.draw(|f| ui(f, &app))?;
 
-        match event::read()? {
-            Event::Key(key) => {
-                if key.kind == KeyEventKind::Press {
+        let timeout = TICK_RATE.saturating_sub(app.last_tick.elapsed());
+        if event::poll(timeout)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
                     if app.input_mode {
                         match key.code {
                             KeyCode::Enter => {
                                 let url = app.url.clone();
-                                let fetch_result = reqwest::blocking::get(&url);
-                                match fetch_result {
-                                    Ok(mut resp) => {
-                                        if resp.status().is_success() {
-                                            match resp.text() {
-                                                Ok(text) => {
-                                                    if let Ok(mut file) = std::fs::File::create("fetched.html") {
-                                                        if file.write_all(text.as_bytes()).is_ok() {
-                                                            let song = parse_html(&text);
-                                                            app.song = Some(song);
-                                                            let chord_count = app.song.as_ref().unwrap().chords.len();
-                                                            let title = app.song.as_ref().unwrap().title.as_ref().unwrap_or(&"Unknown".to_string());
-                                                            let artist = app.song.as_ref().unwrap().artist.as_ref().unwrap_or(&"Unknown".to_string());
-                                                            app.message = format!("Saved to fetched.html. Parsed {} chords for '{}' by {}", chord_count, title, artist);
-                                                        } else {
-                                                            app.message = "Error writing to file".to_string();
-                                                        }
-                                                    } else {
-                                                        app.message = "Error creating file".to_string();
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    app.message = format!("Error reading response: {}", e);
-                                                }
-                                            }
-                                        } else {
-                                            app.message = format!("HTTP error: {}", resp.status());
-                                        }
-                                    }
-                                    Err(e) => {
-                                        app.message = format!("Error fetching URL: {}", e);
-                                    }
-                                }
+                                begin_fetch(&mut app, url);
                                 app.input_mode = false;
                             }
                             KeyCode::Char(c) => {
@@ -152,6 +231,68 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                             }
                             _ => {}
                         }
+                    } else if app.search_mode {
+                        match key.code {
+                            KeyCode::Enter => {
+                                let query = app.search_query.clone();
+                                begin_search(&mut app, query);
+                                app.search_mode = false;
+                            }
+                            KeyCode::Char(c) => {
+                                app.search_query.push(c);
+                            }
+                            KeyCode::Backspace => {
+                                app.search_query.pop();
+                            }
+                            KeyCode::Esc => {
+                                app.search_mode = false;
+                            }
+                            _ => {}
+                        }
+                    } else if !app.search_results.is_empty() {
+                        match key.code {
+                            KeyCode::Char('j')
+                                if app.selected_result + 1 < app.search_results.len() =>
+                            {
+                                app.selected_result += 1;
+                            }
+                            KeyCode::Char('k') => {
+                                app.selected_result = app.selected_result.saturating_sub(1);
+                            }
+                            KeyCode::Enter => {
+                                let url = app.search_results[app.selected_result].url.clone();
+                                begin_fetch(&mut app, url);
+                                app.search_results.clear();
+                            }
+                            KeyCode::Esc => {
+                                app.search_results.clear();
+                            }
+                            KeyCode::Char('q') => return Ok(()),
+                            _ => {}
+                        }
+                    } else if app.browsing_library {
+                        match key.code {
+                            KeyCode::Char('j')
+                                if app.selected_library + 1 < app.store.songs.len() =>
+                            {
+                                app.selected_library += 1;
+                            }
+                            KeyCode::Char('k') => {
+                                app.selected_library = app.selected_library.saturating_sub(1);
+                            }
+                            KeyCode::Enter => {
+                                if let Some(song) = app.store.songs.get(app.selected_library) {
+                                    app.song = Some(song.clone());
+                                    app.transpose_offset = 0;
+                                    app.browsing_library = false;
+                                }
+                            }
+                            KeyCode::Esc => {
+                                app.browsing_library = false;
+                            }
+                            KeyCode::Char('q') => return Ok(()),
+                            _ => {}
+                        }
                     } else {
                         match key.code {
                             KeyCode::Char('q') => return Ok(()),
@@ -160,43 +301,69 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                                 app.url.clear();
                                 app.message.clear();
                             }
+                            KeyCode::Char('s') => {
+                                app.search_mode = true;
+                                app.search_query.clear();
+                                app.message.clear();
+                            }
+                            KeyCode::Char('l') => {
+                                app.browsing_library = true;
+                                app.selected_library = 0;
+                            }
+                            KeyCode::Char('p') => {
+                                app.auto_scroll = !app.auto_scroll;
+                                app.scroll_paused = false;
+                            }
+                            KeyCode::Char('c') => {
+                                app.show_diagrams = !app.show_diagrams;
+                            }
+                            KeyCode::Char(' ') if app.auto_scroll => {
+                                app.scroll_paused = !app.scroll_paused;
+                            }
+                            KeyCode::Char(']') => {
+                                app.scroll_speed = app.scroll_speed.saturating_add(1);
+                            }
+                            KeyCode::Char('[') => {
+                                app.scroll_speed = app.scroll_speed.saturating_sub(1).max(1);
+                            }
+                            KeyCode::Char('+') => {
+                                if let Some(song) = app.song.as_mut() {
+                                    song.transpose(1);
+                                    app.transpose_offset += 1;
+                                }
+                            }
+                            KeyCode::Char('-') => {
+                                if let Some(song) = app.song.as_mut() {
+                                    song.transpose(-1);
+                                    app.transpose_offset -= 1;
+                                }
+                            }
                             _ => {}
                         }
                     }
                 }
-            }
-            Event::Paste(data) => {
-                if app.input_mode {
-                    app.url.push_str(&data);
+                Event::Paste(data) => {
+                    if app.input_mode {
+                        app.url.push_str(&data);
+                    } else if app.search_mode {
+                        app.search_query.push_str(&data);
+                    }
                 }
+                _ => {}
             }
-            _ => {}
         }
-    }
-}
 
-fn ui(f: &mut Frame, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage(10),
-            Constraint::Percentage(80),
-            Constraint::Percentage(10),
-        ])
-        .split(f.area());
-
-    let keybinds = Paragraph::new("u: Enter URL\nq: Quit")
-        .block(Block::default().title("Keybinds").borders(Borders::ALL));
-    f.render_widget(keybinds, chunks[0]);
-
-    if app.input_mode {
-        let input = Paragraph::new(app.url.as_str())
-            .style(Style::default().fg(Color::Yellow))
-            .block(Block::default().title("URL Input").borders(Borders::ALL));
-        f.render_widget(input, chunks[1]);
-    } else {
-        let message = Paragraph::new(app.message.as_str())
-            .block(Block::default().title("Message").borders(Borders::ALL));
-        f.render_widget(message, chunks[1]);
+        poll_fetch(&mut app);
+        poll_search(&mut app);
+
+        if app.last_tick.elapsed() >= TICK_RATE {
+            if app.auto_scroll && !app.scroll_paused {
+                app.scroll_offset = app.scroll_offset.saturating_add(app.scroll_speed);
+            }
+            if app.fetching || app.searching {
+                app.spinner_frame = app.spinner_frame.wrapping_add(1);
+            }
+            app.last_tick = Instant::now();
+        }
     }
 }
@@ -0,0 +1,126 @@
+use std::sync::mpsc;
+use std::thread;
+
+use reqwest::Url;
+use scraper::{Html, Selector};
+
+use crate::http;
+
+/// One row of Ultimate Guitar's search results, enough to let the user
+/// pick a tab without having to go find and paste its URL themselves.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub title: String,
+    pub artist: String,
+    pub kind: String,
+    pub rating: String,
+    pub url: String,
+}
+
+/// Result of a background search, picked up by `poll_search` once the
+/// spawned thread finishes.
+pub enum SearchOutcome {
+    Success(Vec<SearchResult>),
+    Failure(String),
+}
+
+/// Runs `search` on a background thread so the event loop never blocks on
+/// the network, mirroring `http::spawn_fetch`.
+pub fn spawn_search(query: String) -> mpsc::Receiver<SearchOutcome> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let outcome = match search(&query) {
+            Ok(results) => SearchOutcome::Success(results),
+            Err(e) => SearchOutcome::Failure(e),
+        };
+        let _ = tx.send(outcome);
+    });
+    rx
+}
+
+pub fn search(query: &str) -> Result<Vec<SearchResult>, String> {
+    let mut endpoint = Url::parse("https://www.ultimate-guitar.com/search.php")
+        .map_err(|e| format!("Error building search URL: {e}"))?;
+    endpoint
+        .query_pairs_mut()
+        .append_pair("search_type", "title")
+        .append_pair("value", query);
+
+    let html = http::build_client()
+        .get(endpoint)
+        .send()
+        .map_err(|e| format!("Error fetching search results: {e}"))?
+        .text()
+        .map_err(|e| format!("Error reading search response: {e}"))?;
+
+    Ok(parse_search_results(&html))
+}
+
+fn parse_search_results(html: &str) -> Vec<SearchResult> {
+    let document = Html::parse_document(html);
+
+    // Placeholder selectors, like the ones in song::parse_html; may need
+    // adjusting to match UG's actual results markup.
+    let row_selector = Selector::parse("a.search-result").unwrap();
+    let title_selector = Selector::parse(".title").unwrap();
+    let artist_selector = Selector::parse(".artist").unwrap();
+    let kind_selector = Selector::parse(".type").unwrap();
+    let rating_selector = Selector::parse(".rating").unwrap();
+
+    document
+        .select(&row_selector)
+        .filter_map(|row| {
+            let url = row.value().attr("href")?.to_string();
+            let text_of = |selector: &Selector| -> String {
+                row.select(selector)
+                    .next()
+                    .map(|el| el.text().collect())
+                    .unwrap_or_default()
+            };
+            Some(SearchResult {
+                title: text_of(&title_selector),
+                artist: text_of(&artist_selector),
+                kind: text_of(&kind_selector),
+                rating: text_of(&rating_selector),
+                url,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_search_results_extracts_title_artist_kind_rating_and_url() {
+        let html = r#"<html><body>
+<a class="search-result" href="https://tabs.ultimate-guitar.com/tab/1">
+  <span class="title">Wonderwall</span>
+  <span class="artist">Oasis</span>
+  <span class="type">Chords</span>
+  <span class="rating">4.8</span>
+</a>
+</body></html>"#;
+
+        let results = parse_search_results(html);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Wonderwall");
+        assert_eq!(results[0].artist, "Oasis");
+        assert_eq!(results[0].kind, "Chords");
+        assert_eq!(results[0].rating, "4.8");
+        assert_eq!(results[0].url, "https://tabs.ultimate-guitar.com/tab/1");
+    }
+
+    #[test]
+    fn parse_search_results_skips_rows_without_an_href() {
+        let html = r#"<html><body>
+<a class="search-result">
+  <span class="title">No Link</span>
+</a>
+</body></html>"#;
+
+        assert!(parse_search_results(html).is_empty());
+    }
+}
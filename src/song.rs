@@ -0,0 +1,215 @@
+use scraper::{node::Node, CaseSensitivity, ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use crate::chord::{self, Chord};
+
+/// A single row of a rendered tab chart.
+///
+/// `ChordLine` and `Lyric` are emitted as a pair for the classic two-row
+/// "chord line / lyric line" layout: the chord line carries the column
+/// offset of each chord so it can be redrawn directly above the syllable
+/// it belongs to, rather than being flattened into a bare name list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TabLine {
+    ChordLine(Vec<(usize, String)>),
+    Lyric(String),
+    Section(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Song {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub source_url: Option<String>,
+    pub tab_lines: Vec<TabLine>,
+}
+
+impl Song {
+    /// Shifts every parseable chord in the chart by `semitones`, re-spelling
+    /// the root (and slash bass, if present) while leaving the suffix alone.
+    /// Chord text that doesn't parse as a chord is left untouched. The
+    /// song's key — taken from the chart's first (tonic) chord — decides
+    /// whether the new spellings use flats or sharps, so the whole chart
+    /// stays consistent as it transposes.
+    pub fn transpose(&mut self, semitones: i32) {
+        let use_flats = self
+            .tonic_chord()
+            .is_some_and(|chord| chord::key_prefers_flats(&chord.root));
+
+        for line in &mut self.tab_lines {
+            if let TabLine::ChordLine(chords) = line {
+                for (_, name) in chords.iter_mut() {
+                    if let Some(chord) = Chord::parse(name) {
+                        *name = chord.transpose(semitones, use_flats).render();
+                    }
+                }
+            }
+        }
+    }
+
+    /// The first parseable chord in the chart, treated as the song's key.
+    fn tonic_chord(&self) -> Option<Chord> {
+        self.tab_lines.iter().find_map(|line| match line {
+            TabLine::ChordLine(chords) => chords.iter().find_map(|(_, name)| Chord::parse(name)),
+            _ => None,
+        })
+    }
+
+    /// Distinct chord names that appear in the chart, in first-seen order.
+    pub fn distinct_chords(&self) -> Vec<String> {
+        let mut seen = Vec::new();
+        for line in &self.tab_lines {
+            if let TabLine::ChordLine(chords) = line {
+                for (_, name) in chords {
+                    if !seen.contains(name) {
+                        seen.push(name.clone());
+                    }
+                }
+            }
+        }
+        seen
+    }
+}
+
+pub fn parse_html(html: &str) -> Song {
+    let document = Html::parse_document(html);
+
+    // Extract title
+    let title_selector = Selector::parse("title").unwrap();
+    let title = document
+        .select(&title_selector)
+        .next()
+        .map(|t| t.inner_html());
+
+    // Extract artist (assuming from meta or h1, adjust selector as needed)
+    let artist_selector = Selector::parse("meta[name='og:site_name']").unwrap(); // Placeholder, may need better selector
+    let artist = document
+        .select(&artist_selector)
+        .next()
+        .and_then(|a| a.value().attr("content"))
+        .map(String::from);
+
+    // The tab pro view renders the chart as a single pre-formatted container
+    // with chord names wrapped in span.yvpjZ inline with the surrounding
+    // lyric text. Walking it in document order lets us keep chords and
+    // lyrics aligned instead of collecting chords into a flat list.
+    let tab_selector = Selector::parse("pre").unwrap();
+    let tab_lines = document
+        .select(&tab_selector)
+        .next()
+        .map(collect_tab_lines)
+        .unwrap_or_default();
+
+    Song {
+        title,
+        artist,
+        source_url: None,
+        tab_lines,
+    }
+}
+
+fn collect_tab_lines(container: ElementRef) -> Vec<TabLine> {
+    let mut lines = Vec::new();
+    let mut current_text = String::new();
+    let mut current_chords: Vec<(usize, String)> = Vec::new();
+
+    walk_tab_node(
+        container,
+        &mut current_text,
+        &mut current_chords,
+        &mut lines,
+    );
+    flush_tab_line(&mut current_text, &mut current_chords, &mut lines);
+
+    lines
+}
+
+fn walk_tab_node(
+    node: ElementRef,
+    current_text: &mut String,
+    current_chords: &mut Vec<(usize, String)>,
+    lines: &mut Vec<TabLine>,
+) {
+    for child in node.children() {
+        match child.value() {
+            Node::Text(text) => {
+                let mut first = true;
+                for segment in text.split('\n') {
+                    if !first {
+                        flush_tab_line(current_text, current_chords, lines);
+                    }
+                    current_text.push_str(segment);
+                    first = false;
+                }
+            }
+            Node::Element(el)
+                if el.name() == "span" && el.has_class("yvpjZ", CaseSensitivity::CaseSensitive) =>
+            {
+                let chord_ref = ElementRef::wrap(child).unwrap();
+                let name = chord_ref.text().collect::<String>();
+                let offset = current_text.chars().count();
+                current_text.push_str(&name);
+                current_chords.push((offset, name));
+            }
+            Node::Element(_) => {
+                if let Some(child_ref) = ElementRef::wrap(child) {
+                    walk_tab_node(child_ref, current_text, current_chords, lines);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn flush_tab_line(
+    current_text: &mut String,
+    current_chords: &mut Vec<(usize, String)>,
+    lines: &mut Vec<TabLine>,
+) {
+    if !current_chords.is_empty() {
+        lines.push(TabLine::ChordLine(std::mem::take(current_chords)));
+    } else {
+        let trimmed = current_text.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            lines.push(TabLine::Section(trimmed.to_string()));
+        } else {
+            lines.push(TabLine::Lyric(current_text.clone()));
+        }
+    }
+    current_text.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_html_keeps_chord_columns_aligned_with_the_lyric_line_below() {
+        let html = r#"<html><body><pre><span class="yvpjZ">C</span>    <span class="yvpjZ">G</span>
+Hello world
+</pre></body></html>"#;
+
+        let song = parse_html(html);
+
+        assert_eq!(
+            song.tab_lines,
+            vec![
+                TabLine::ChordLine(vec![(0, "C".to_string()), (5, "G".to_string())]),
+                TabLine::Lyric("Hello world".to_string()),
+                TabLine::Lyric(String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_html_recognizes_section_markers() {
+        let html = "<html><body><pre>[Chorus]</pre></body></html>";
+
+        let song = parse_html(html);
+
+        assert_eq!(
+            song.tab_lines,
+            vec![TabLine::Section("[Chorus]".to_string())]
+        );
+    }
+}
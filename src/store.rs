@@ -0,0 +1,113 @@
+use std::{fs, io, path::PathBuf};
+
+use crate::song::Song;
+
+/// A local library of previously fetched songs, persisted as JSON under the
+/// platform config dir so they can be reopened without a network round trip.
+pub struct Store {
+    path: PathBuf,
+    pub songs: Vec<Song>,
+}
+
+impl Store {
+    pub fn load() -> Store {
+        let path = store_path();
+        let songs = fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default();
+        Store { path, songs }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(&self.songs).unwrap_or_default();
+        fs::write(&self.path, data)
+    }
+
+    /// Inserts `song`, replacing any existing entry with the same source
+    /// URL, then persists the store to disk.
+    pub fn insert(&mut self, song: Song) {
+        if let Some(url) = &song.source_url {
+            self.songs
+                .retain(|s| s.source_url.as_deref() != Some(url.as_str()));
+        }
+        self.songs.push(song);
+        let _ = self.save();
+    }
+}
+
+fn store_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("ultimate-guitar-player")
+        .join("library.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_song(url: &str) -> Song {
+        Song {
+            title: Some("Title".to_string()),
+            artist: Some("Artist".to_string()),
+            source_url: Some(url.to_string()),
+            tab_lines: Vec::new(),
+        }
+    }
+
+    fn temp_store(name: &str, songs: Vec<Song>) -> Store {
+        Store {
+            path: std::env::temp_dir().join(name),
+            songs,
+        }
+    }
+
+    #[test]
+    fn insert_replaces_existing_entry_with_the_same_url() {
+        let mut store = temp_store(
+            "ultimate-guitar-player-test-dedupe.json",
+            vec![sample_song("https://example.com/a")],
+        );
+
+        store.insert(sample_song("https://example.com/a"));
+
+        assert_eq!(store.songs.len(), 1);
+        let _ = fs::remove_file(&store.path);
+    }
+
+    #[test]
+    fn insert_keeps_entries_with_distinct_urls() {
+        let mut store = temp_store(
+            "ultimate-guitar-player-test-distinct.json",
+            vec![sample_song("https://example.com/a")],
+        );
+
+        store.insert(sample_song("https://example.com/b"));
+
+        assert_eq!(store.songs.len(), 2);
+        let _ = fs::remove_file(&store.path);
+    }
+
+    #[test]
+    fn save_then_load_round_trips_songs() {
+        let store = temp_store(
+            "ultimate-guitar-player-test-roundtrip.json",
+            vec![sample_song("https://example.com/a")],
+        );
+        store.save().unwrap();
+
+        let reloaded: Vec<Song> =
+            serde_json::from_str(&fs::read_to_string(&store.path).unwrap()).unwrap();
+
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(
+            reloaded[0].source_url.as_deref(),
+            Some("https://example.com/a")
+        );
+        let _ = fs::remove_file(&store.path);
+    }
+}
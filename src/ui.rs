@@ -0,0 +1,239 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Text},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::fretboard::{self, Diagram};
+use crate::song::TabLine;
+use crate::App;
+
+pub fn ui(f: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(10),
+            Constraint::Percentage(80),
+            Constraint::Percentage(10),
+        ])
+        .split(f.area());
+
+    let scroll_state = if !app.auto_scroll {
+        "off".to_string()
+    } else if app.scroll_paused {
+        format!("paused, speed {}", app.scroll_speed)
+    } else {
+        format!("playing, speed {}", app.scroll_speed)
+    };
+    let keybinds = Paragraph::new(format!(
+        "u: Enter URL\ns: Search\nl: Library\nq: Quit\n+/-: Transpose ({:+})\np: Auto-scroll ({scroll_state})  [/]: Speed  Space: Pause\nc: Chord diagrams",
+        app.transpose_offset
+    ))
+    .block(Block::default().title("Keybinds").borders(Borders::ALL));
+    f.render_widget(keybinds, chunks[0]);
+
+    if app.input_mode {
+        let input = Paragraph::new(app.url.as_str())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().title("URL Input").borders(Borders::ALL));
+        f.render_widget(input, chunks[1]);
+    } else if app.search_mode {
+        let input = Paragraph::new(app.search_query.as_str())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().title("Search").borders(Borders::ALL));
+        f.render_widget(input, chunks[1]);
+    } else if !app.search_results.is_empty() {
+        let results = Paragraph::new(render_search_results(app)).block(
+            Block::default()
+                .title("Search Results (j/k, Enter)")
+                .borders(Borders::ALL),
+        );
+        f.render_widget(results, chunks[1]);
+    } else if app.browsing_library {
+        let library = Paragraph::new(render_library(app)).block(
+            Block::default()
+                .title("Library (j/k, Enter)")
+                .borders(Borders::ALL),
+        );
+        f.render_widget(library, chunks[1]);
+    } else if app.fetching || app.searching {
+        let spinner = ['|', '/', '-', '\\'][app.spinner_frame % 4];
+        let message = Paragraph::new(format!("{} {spinner}", app.message))
+            .block(Block::default().title("Message").borders(Borders::ALL));
+        f.render_widget(message, chunks[1]);
+    } else if app.show_diagrams && app.song.is_some() {
+        render_diagrams(f, chunks[1], &app.song.as_ref().unwrap().distinct_chords());
+    } else if let Some(song) = &app.song {
+        let chart = Paragraph::new(render_tab_lines(&song.tab_lines))
+            .block(Block::default().title("Chart").borders(Borders::ALL))
+            .scroll((app.scroll_offset, 0));
+        f.render_widget(chart, chunks[1]);
+    } else {
+        let message = Paragraph::new(app.message.as_str())
+            .block(Block::default().title("Message").borders(Borders::ALL));
+        f.render_widget(message, chunks[1]);
+    }
+}
+
+const FRET_SPAN: u8 = 4;
+
+fn render_diagrams(f: &mut Frame, area: ratatui::layout::Rect, chord_names: &[String]) {
+    if chord_names.is_empty() {
+        let empty = Paragraph::new("No chords to diagram.").block(
+            Block::default()
+                .title("Chord Diagrams")
+                .borders(Borders::ALL),
+        );
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let block = Block::default()
+        .title("Chord Diagrams")
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let constraints: Vec<Constraint> = chord_names
+        .iter()
+        .map(|_| Constraint::Ratio(1, chord_names.len() as u32))
+        .collect();
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(inner);
+
+    for (name, &column) in chord_names.iter().zip(columns.iter()) {
+        let diagram = fretboard::diagram_for(name);
+        let lines = render_diagram_lines(&diagram);
+        f.render_widget(Paragraph::new(lines), column);
+    }
+}
+
+fn render_diagram_lines(diagram: &Diagram) -> Text<'_> {
+    let mut lines = vec![Line::styled(
+        diagram.name.clone(),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+
+    // The grid below starts at `base_fret`, never below fret 1; a shape rooted on an open
+    // string (base_fret 0) shows its open notes via the marker row instead
+    // of a "0fr" label or row.
+    let display_fret = diagram.base_fret.max(1);
+
+    if display_fret > 1 {
+        lines.push(Line::raw(format!("{display_fret}fr")));
+    }
+
+    let markers = diagram
+        .frets
+        .iter()
+        .map(|fret| match fret {
+            None => 'x',
+            Some(0) if display_fret == 1 => 'o',
+            _ => ' ',
+        })
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    lines.push(Line::raw(markers));
+
+    for row in 0..FRET_SPAN {
+        let fret_num = display_fret + row;
+        let cells = diagram
+            .frets
+            .iter()
+            .map(|fret| match fret {
+                Some(n) if *n == fret_num => "o",
+                _ => "-",
+            })
+            .collect::<Vec<_>>()
+            .join("-");
+        lines.push(Line::raw(cells));
+    }
+
+    Text::from(lines)
+}
+
+fn render_library(app: &App) -> Text<'_> {
+    let selected_style = Style::default().fg(Color::Black).bg(Color::Cyan);
+
+    if app.store.songs.is_empty() {
+        return Text::from(Line::raw(
+            "No saved songs yet. Fetch one to add it to your library.",
+        ));
+    }
+
+    let lines = app
+        .store
+        .songs
+        .iter()
+        .enumerate()
+        .map(|(i, song)| {
+            let title = song.title.as_deref().unwrap_or("Unknown");
+            let artist = song.artist.as_deref().unwrap_or("Unknown");
+            let text = format!("{title} - {artist}");
+            if i == app.selected_library {
+                Line::styled(text, selected_style)
+            } else {
+                Line::raw(text)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Text::from(lines)
+}
+
+fn render_search_results(app: &App) -> Text<'_> {
+    let selected_style = Style::default().fg(Color::Black).bg(Color::Cyan);
+
+    let lines = app
+        .search_results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let text = format!(
+                "{} - {} [{}] ({})",
+                result.title, result.artist, result.kind, result.rating
+            );
+            if i == app.selected_result {
+                Line::styled(text, selected_style)
+            } else {
+                Line::raw(text)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Text::from(lines)
+}
+
+fn render_tab_lines(tab_lines: &[TabLine]) -> Text<'_> {
+    let chord_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let section_style = Style::default()
+        .fg(Color::Magenta)
+        .add_modifier(Modifier::BOLD);
+
+    let lines = tab_lines
+        .iter()
+        .map(|tab_line| match tab_line {
+            TabLine::ChordLine(chords) => {
+                let mut row = String::new();
+                for (offset, name) in chords {
+                    if row.chars().count() < *offset {
+                        row.push_str(&" ".repeat(offset - row.chars().count()));
+                    }
+                    row.push_str(name);
+                }
+                Line::styled(row, chord_style)
+            }
+            TabLine::Lyric(text) => Line::raw(text.clone()),
+            TabLine::Section(text) => Line::styled(text.clone(), section_style),
+        })
+        .collect::<Vec<_>>();
+
+    Text::from(lines)
+}